@@ -1,9 +1,64 @@
 use clap::{Arg, Command};
 use colored::Colorize;
 use eth_passphrase_backup::restore_from_share;
+use eth_passphrase_backup::{derive_address, DEFAULT_DERIVATION_PATH};
 use eth_passphrase_backup::{get_share, Passphrase};
+use rand::rngs::OsRng;
+use rand::RngCore;
 use std::process;
 
+fn derive_address_or_exit(words: &[&str], path: &str) -> String {
+    match derive_address(words, "", path) {
+        Ok(address) => address,
+        Err(e) => {
+            println!("{}", format!("Failed to derive wallet address: {}", e).red());
+            process::exit(1);
+        }
+    }
+}
+
+fn value_of_u8_or_exit(matches: &clap::ArgMatches, name: &str, flag_label: &str) -> u8 {
+    match matches.value_of_t::<u8>(name) {
+        Ok(value) => value,
+        Err(_) => {
+            println!("{}", format!("{} must be a number between 0 and 255", flag_label).red());
+            process::exit(1);
+        }
+    }
+}
+
+fn parse_u8_or_exit(value: &str, flag_label: &str) -> u8 {
+    match value.parse() {
+        Ok(value) => value,
+        Err(_) => {
+            println!("{}", format!("{} must be a number between 0 and 255", flag_label).red());
+            process::exit(1);
+        }
+    }
+}
+
+fn build_shares_or_exit<'a>(
+    passphrase: Passphrase<'a>,
+    num_shares_to_create: u8,
+    required_num_shares_to_recover: u8,
+) -> Vec<Vec<&'a str>> {
+    if required_num_shares_to_recover < 2 {
+        println!(
+            "{}",
+            "Threshold must be at least 2; a threshold of 1 lets a single share reveal the secret.".red()
+        );
+        process::exit(1);
+    }
+
+    match get_share(passphrase, num_shares_to_create, required_num_shares_to_recover) {
+        Ok(shares) => shares,
+        Err(e) => {
+            println!("{}", format!("Failed to build shares: {}", e).red());
+            process::exit(1);
+        }
+    }
+}
+
 fn main() {
     let matches = Command::new("sss")
         .about("Ethereum HD wallet passphase backup utilities")
@@ -23,6 +78,27 @@ fn main() {
                         .help("Passphrase to generate share from.")
                         .takes_value(true)
                         .required(true),
+                )
+                .arg(
+                    Arg::new("path")
+                        .long("path")
+                        .help("BIP32 derivation path for the address shown.")
+                        .takes_value(true)
+                        .default_value(DEFAULT_DERIVATION_PATH),
+                )
+                .arg(
+                    Arg::new("shares")
+                        .long("shares")
+                        .help("Total number of shares (n) to create.")
+                        .takes_value(true)
+                        .default_value("5"),
+                )
+                .arg(
+                    Arg::new("threshold")
+                        .long("threshold")
+                        .help("Number of shares (k) required to recover the passphrase.")
+                        .takes_value(true)
+                        .default_value("3"),
                 ),
         )
         .subcommand(
@@ -37,8 +113,43 @@ fn main() {
                         .help("Backup share that generated from passphrase.")
                         .takes_value(true)
                         .multiple_values(true)
-                        .number_of_values(3)
                         .required(true),
+                )
+                .arg(
+                    Arg::new("path")
+                        .long("path")
+                        .help("BIP32 derivation path for the address shown.")
+                        .takes_value(true)
+                        .default_value(DEFAULT_DERIVATION_PATH),
+                )
+                .arg(
+                    Arg::new("threshold")
+                        .long("threshold")
+                        .help("Number of shares (k) that were required to recover the passphrase.")
+                        .takes_value(true)
+                        .default_value("3"),
+                ),
+        )
+        .subcommand(
+            Command::new("generate")
+                .short_flag('g')
+                .long_flag("generate")
+                .about("Generate a fresh checksum-valid mnemonic")
+                .arg(
+                    Arg::new("words")
+                        .long("words")
+                        .help("Number of words in the generated mnemonic.")
+                        .takes_value(true)
+                        .possible_values(["12", "24"])
+                        .default_value("12"),
+                )
+                .arg(
+                    Arg::new("split")
+                        .long("split")
+                        .help("Also emit N backup shares requiring M to recover.")
+                        .takes_value(true)
+                        .number_of_values(2)
+                        .value_names(&["N", "M"]),
                 ),
         )
         .get_matches();
@@ -55,11 +166,24 @@ fn main() {
                     process::exit(1);
                 }
 
-                let passphrase = Passphrase::from_words(&words)
-                    .expect(&format!("{}", "Failed to parse passphrase.".red()));
+                let passphrase = match Passphrase::from_words(&words) {
+                    Ok(passphrase) => passphrase,
+                    Err(e) => {
+                        println!("{}", format!("Failed to parse passphrase: {}", e).red());
+                        process::exit(1);
+                    }
+                };
 
-                let shares = get_share(passphrase, 5, 3)
-                    .expect(&format!("{}", "Failed to build shares".red()));
+                let path = backup_matches.value_of("path").unwrap();
+                let address = derive_address_or_exit(&words, path);
+                println!("Wallet address: {}", address.green());
+
+                let num_shares_to_create = value_of_u8_or_exit(backup_matches, "shares", "--shares");
+                let required_num_shares_to_recover =
+                    value_of_u8_or_exit(backup_matches, "threshold", "--threshold");
+
+                let shares =
+                    build_shares_or_exit(passphrase, num_shares_to_create, required_num_shares_to_recover);
 
                 println!("Shares are:");
                 for (i, share) in shares.iter().enumerate() {
@@ -86,8 +210,19 @@ fn main() {
                     shares_slices.push(words);
                 }
 
-                let passphrase = restore_from_share(&shares_slices)
-                    .expect(&format!("{}", "Failed to restore passphrase.").red());
+                let required_num_shares_to_recover =
+                    value_of_u8_or_exit(restore_matches, "threshold", "--threshold");
+
+                let passphrase = match restore_from_share(&shares_slices, required_num_shares_to_recover) {
+                    Ok(passphrase) => passphrase,
+                    Err(e) => {
+                        println!(
+                            "{}",
+                            format!("Failed to restore passphrase: {} (check each share was typed correctly)", e).red()
+                        );
+                        process::exit(1);
+                    }
+                };
                 let words = passphrase
                     .get_words()
                     .expect(&format!("{}", "Failed to extract words from passphrase.").red());
@@ -96,9 +231,51 @@ fn main() {
                     format!("🔑 Original passphrase is: {}", words.join(" ")).green()
                 );
 
+                let path = restore_matches.value_of("path").unwrap();
+                let address = derive_address_or_exit(&words, path);
+                println!("Wallet address: {}", address.green());
+
                 return;
             }
         }
+        Some(("generate", generate_matches)) => {
+            let num_words: usize = generate_matches.value_of_t("words").unwrap();
+            let entropy_len = if num_words == 24 { 32 } else { 16 };
+
+            let mut entropy = vec![0u8; entropy_len];
+            OsRng.fill_bytes(&mut entropy);
+
+            let passphrase = Passphrase::from_bytes(&entropy)
+                .expect(&format!("{}", "Failed to build passphrase from entropy.".red()));
+            let words = passphrase
+                .get_words()
+                .expect(&format!("{}", "Failed to extract words from passphrase.").red());
+            println!(
+                "{}",
+                format!("🔑 Generated passphrase is: {}", words.join(" ")).green()
+            );
+
+            if let Some(mut split_values) = generate_matches.values_of("split") {
+                let num_shares_to_create = parse_u8_or_exit(split_values.next().unwrap(), "N in --split N M");
+                let required_num_shares_to_recover =
+                    parse_u8_or_exit(split_values.next().unwrap(), "M in --split N M");
+
+                let passphrase = Passphrase::from_words(&words)
+                    .expect(&format!("{}", "Failed to parse generated passphrase.".red()));
+                let shares =
+                    build_shares_or_exit(passphrase, num_shares_to_create, required_num_shares_to_recover);
+
+                println!("Shares are:");
+                for (i, share) in shares.iter().enumerate() {
+                    println!(
+                        "{}",
+                        format!("🔐 Share {} is: {}", i + 1, share.join(" ")).green()
+                    );
+                }
+            }
+
+            return;
+        }
         _ => unreachable!(), // If all subcommands are defined above, anything else is unreachable
     }
 }
@@ -0,0 +1,126 @@
+//! Derives the Ethereum address for a BIP39 mnemonic, so a user can
+//! visually confirm a backup or restore produced the wallet they expect
+//! (the same check as running `ethkey public` / `ethkey address`).
+
+use hmac::{Hmac, Mac};
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use k256::elliptic_curve::PrimeField;
+use k256::{ProjectivePoint, Scalar};
+use pbkdf2::pbkdf2_hmac;
+use sha2::Sha512;
+use sha3::{Digest, Keccak256};
+use std::fmt;
+
+const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+/// The standard Ethereum BIP44 derivation path.
+pub const DEFAULT_DERIVATION_PATH: &str = "m/44'/60'/0'/0/0";
+
+#[derive(Debug)]
+pub enum EthereumErr {
+    InvalidPath(String),
+}
+
+impl fmt::Display for EthereumErr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EthereumErr::InvalidPath(path) => write!(f, "Invalid derivation path: {}", path),
+        }
+    }
+}
+
+struct ExtendedKey {
+    key: Scalar,
+    chain_code: [u8; 32],
+}
+
+fn bip39_seed(words: &[&str], passphrase: &str) -> [u8; 64] {
+    let mnemonic = words.join(" ");
+    let salt = format!("mnemonic{}", passphrase);
+    let mut seed = [0u8; 64];
+    pbkdf2_hmac::<Sha512>(mnemonic.as_bytes(), salt.as_bytes(), 2048, &mut seed);
+    seed
+}
+
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> [u8; 64] {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts a key of any length");
+    for chunk in data {
+        mac.update(chunk);
+    }
+    mac.finalize().into_bytes().into()
+}
+
+fn scalar_from_bytes(bytes: &[u8]) -> Scalar {
+    let array: [u8; 32] = bytes.try_into().expect("HMAC-SHA512 half is always 32 bytes");
+    Scalar::from_repr(array.into()).unwrap()
+}
+
+fn master_key(seed: &[u8]) -> ExtendedKey {
+    let i = hmac_sha512(b"Bitcoin seed", &[seed]);
+    let (il, ir) = i.split_at(32);
+    ExtendedKey {
+        key: scalar_from_bytes(il),
+        chain_code: ir.try_into().unwrap(),
+    }
+}
+
+fn derive_child(parent: &ExtendedKey, index: u32) -> ExtendedKey {
+    let i = if index >= HARDENED_OFFSET {
+        hmac_sha512(
+            &parent.chain_code,
+            &[&[0u8], &parent.key.to_bytes(), &index.to_be_bytes()],
+        )
+    } else {
+        let point = ProjectivePoint::GENERATOR * parent.key;
+        let encoded = point.to_affine().to_encoded_point(true);
+        hmac_sha512(&parent.chain_code, &[encoded.as_bytes(), &index.to_be_bytes()])
+    };
+
+    let (il, ir) = i.split_at(32);
+    ExtendedKey {
+        key: scalar_from_bytes(il) + parent.key,
+        chain_code: ir.try_into().unwrap(),
+    }
+}
+
+fn parse_path(path: &str) -> Result<Vec<u32>, EthereumErr> {
+    let mut segments = path.split('/');
+    if segments.next() != Some("m") {
+        return Err(EthereumErr::InvalidPath(path.to_string()));
+    }
+
+    segments
+        .map(|segment| {
+            let (number, hardened) = match segment.strip_suffix('\'') {
+                Some(number) => (number, true),
+                None => (segment, false),
+            };
+            let index: u32 = number
+                .parse()
+                .map_err(|_| EthereumErr::InvalidPath(path.to_string()))?;
+            if hardened {
+                index.checked_add(HARDENED_OFFSET).ok_or_else(|| EthereumErr::InvalidPath(path.to_string()))
+            } else {
+                Ok(index)
+            }
+        })
+        .collect()
+}
+
+/// Derive the `0x…` Ethereum address for `words`/`bip39_passphrase` at
+/// `path` (typically [`DEFAULT_DERIVATION_PATH`]).
+pub fn derive_address(words: &[&str], bip39_passphrase: &str, path: &str) -> Result<String, EthereumErr> {
+    let seed = bip39_seed(words, bip39_passphrase);
+    let mut key = master_key(&seed);
+    for index in parse_path(path)? {
+        key = derive_child(&key, index);
+    }
+
+    let point = ProjectivePoint::GENERATOR * key.key;
+    let encoded = point.to_affine().to_encoded_point(false);
+    // Drop the leading 0x04 uncompressed-point tag before hashing.
+    let public_key = &encoded.as_bytes()[1..];
+
+    let hash = Keccak256::digest(public_key);
+    Ok(format!("0x{}", hex::encode(&hash[12..])))
+}
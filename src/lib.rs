@@ -2,12 +2,19 @@ use sha2::{Digest, Sha256};
 use sharks::{Share, Sharks};
 use std::fmt;
 
+mod feldman;
+pub use feldman::{get_verifiable_share, restore_from_verifiable_share};
+
+mod ethereum;
+pub use ethereum::{derive_address, EthereumErr, DEFAULT_DERIVATION_PATH};
+
 #[derive(Debug)]
 pub enum PassphaseManageErr {
     PassphaseErr(PassphaseErr),
     ShareNumErr,
     ParseByteToShareErr(&'static str),
     RecoverFromSharesErr(String),
+    InvalidShare,
 }
 
 impl fmt::Display for PassphaseManageErr {
@@ -17,6 +24,7 @@ impl fmt::Display for PassphaseManageErr {
             PassphaseManageErr::ShareNumErr => write!(f,"Number of share to create must be greater than required minimum number of shares to recover."),
             PassphaseManageErr::ParseByteToShareErr(e) => write!(f,"Failed to parse worlds to share: {}", e),
             PassphaseManageErr::RecoverFromSharesErr(e) => write!(f,"Failed to recover secret from shares: {}", e),
+            PassphaseManageErr::InvalidShare => write!(f, "Share failed Feldman commitment verification; it is corrupted or was not issued by the dealer."),
         }
     }
 }
@@ -66,8 +74,9 @@ pub fn get_share<'a>(
 
 pub fn restore_from_share<'a>(
     shares_words: &Vec<Vec<&'a str>>,
+    required_num_shares_to_recover: u8,
 ) -> Result<Passphrase<'a>, PassphaseManageErr> {
-    let num_share = shares_words.len() as usize;
+    let num_share = shares_words.len();
     let mut shares: Vec<Share> = Vec::with_capacity(num_share);
     for share_words in shares_words {
         let p = Passphrase::from_words(share_words)?;
@@ -77,9 +86,12 @@ pub fn restore_from_share<'a>(
         shares.push(share);
     }
 
-    let sharks = Sharks(num_share as u8);
+    // `Sharks` must be built from the threshold the dealer used, not the
+    // number of shares supplied here: passing extra shares beyond the
+    // threshold previously made `Sharks(num_share as u8)` mis-recover.
+    let sharks = Sharks(required_num_shares_to_recover);
     let secret = sharks
-        .recover(&shares[0..num_share])
+        .recover(&shares[..])
         .map_err(|e| PassphaseManageErr::RecoverFromSharesErr(e.to_string()))?;
 
     let passphase = Passphrase::from_bytes(&secret)?;
@@ -91,6 +103,7 @@ pub enum PassphaseErr {
     InvalidWord,
     InvalidNumOfWord,
     PassphaseIsEmpty,
+    InvalidChecksum,
     Unexpected(String),
 }
 
@@ -100,6 +113,7 @@ impl fmt::Display for PassphaseErr {
             PassphaseErr::InvalidWord => write!(f, "Invalid world."),
             PassphaseErr::InvalidNumOfWord => write!(f, "Invalid number of word, expecting.",),
             PassphaseErr::PassphaseIsEmpty => write!(f, "Passphase is empty."),
+            PassphaseErr::InvalidChecksum => write!(f, "Checksum mismatch: a word was mistyped or the passphrase/share is corrupted."),
             PassphaseErr::Unexpected(e) => write!(f, "Unexpected: {}", e),
         }
     }
@@ -139,6 +153,31 @@ impl<'a> Passphrase<'a> {
             }
         };
 
+        let bit_len = word_indexs.len() * 11;
+        let mut bit_str = String::with_capacity(bit_len);
+        for index in word_indexs.iter() {
+            bit_str.push_str(&format!("{:011b}", index.0));
+        }
+        let (entropy_bits, checksum_bits) = bit_str.split_at(bit_len - checksum_size);
+
+        let mut entropy = Vec::with_capacity(entropy_bits.len() / 8);
+        let mut byte_begin = 0;
+        while byte_begin + 8 <= entropy_bits.len() {
+            let byte = u8::from_str_radix(&entropy_bits[byte_begin..byte_begin + 8], 2).map_err(|_| {
+                PassphaseErr::Unexpected("Fail when parse binary string.".to_string())
+            })?;
+            entropy.push(byte);
+            byte_begin += 8;
+        }
+
+        let mut hasher = Sha256::new();
+        hasher.update(&entropy);
+        let hash = hasher.finalize();
+        let expected_checksum = format!("{:08b}{:08b}", hash[0], hash[1]);
+        if checksum_bits != &expected_checksum[..checksum_size] {
+            return Err(PassphaseErr::InvalidChecksum);
+        }
+
         Ok(Passphrase {
             wordlist,
             word_indexs,
@@ -261,6 +300,16 @@ impl<'a> Passphrase<'a> {
 #[derive(Clone, PartialEq, Debug, Default)]
 struct WordIndex(u16);
 
+impl WordIndex {
+    fn new(index: u16) -> Result<Self, WordIndexOutOfRange> {
+        if index < 2u16.pow(11) {
+            Ok(Self(index))
+        } else {
+            Err(WordIndexOutOfRange)
+        }
+    }
+}
+
 #[derive(Debug)]
 struct WordIndexOutOfRange;
 
@@ -385,10 +434,23 @@ mod tests {
             let pp = Passphrase::from_words(&pp).expect("Failed to parse passphrase");
 
             let shares = get_share(pp, 2, 1).expect("Failed to generate shares.");
-            let pp = restore_from_share(&shares[0..1].to_vec()).expect("Failed to retore share");
+            let pp = restore_from_share(&shares[0..1].to_vec(), 1).expect("Failed to retore share");
             let ws = pp.get_words().expect("Failed to get words");
             let ws = ws.join(" ");
             assert_eq!(ws, sample);
         }
     }
+
+    #[test]
+    fn test_from_words_rejects_mistyped_word() {
+        // Swap "today" for "toe", two wordlist entries further on, which keeps
+        // the word count and every word valid but breaks the checksum.
+        let pp: Vec<&str> =
+            "gold dress spread awful floor expect ladder high better census indicate toe"
+                .split(" ")
+                .collect();
+
+        let result = Passphrase::from_words(&pp);
+        assert!(matches!(result, Err(PassphaseErr::InvalidChecksum)));
+    }
 }